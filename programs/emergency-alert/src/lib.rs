@@ -11,17 +11,96 @@ pub mod emergency_alert {
         let emergency_alert = &mut ctx.accounts.emergency_alert;
         emergency_alert.authority = ctx.accounts.authority.key();
         emergency_alert.alert_counter = 0;
+        // The deploying authority is the first custodian.
+        emergency_alert.custodians = vec![ctx.accounts.authority.key()];
+        emergency_alert.operators = vec![];
         emergency_alert.bump = ctx.bumps.emergency_alert;
-        
+
         msg!("Emergency Alert System initialized");
         Ok(())
     }
 
+    /// Delegate alert resolution for a single zone to an operator.
+    /// Only a custodian may add operators.
+    pub fn add_operator(
+        ctx: Context<ManageOperator>,
+        operator: Pubkey,
+        zone_id: String,
+    ) -> Result<()> {
+        let emergency_alert = &mut ctx.accounts.emergency_alert;
+        require!(
+            emergency_alert.is_custodian(&ctx.accounts.authority.key()),
+            ErrorCode::Unauthorized
+        );
+
+        emergency_alert.operators.retain(|o| o.pubkey != operator);
+        emergency_alert.operators.push(Operator {
+            pubkey: operator,
+            zone_id: zone_id.clone(),
+        });
+
+        emit!(OperatorAdded { operator, zone_id });
+        Ok(())
+    }
+
+    /// Remove a delegated operator. Only a custodian may remove operators.
+    pub fn remove_operator(ctx: Context<ManageOperator>, operator: Pubkey) -> Result<()> {
+        let emergency_alert = &mut ctx.accounts.emergency_alert;
+        require!(
+            emergency_alert.is_custodian(&ctx.accounts.authority.key()),
+            ErrorCode::Unauthorized
+        );
+
+        emergency_alert.operators.retain(|o| o.pubkey != operator);
+
+        emit!(OperatorRemoved { operator });
+        Ok(())
+    }
+
+    /// Hand the custodian role to a new principal. The caller must already be
+    /// a custodian; it is swapped out for the new one.
+    pub fn transfer_custodian(
+        ctx: Context<ManageOperator>,
+        new_custodian: Pubkey,
+    ) -> Result<()> {
+        let emergency_alert = &mut ctx.accounts.emergency_alert;
+        let caller = ctx.accounts.authority.key();
+        require!(emergency_alert.is_custodian(&caller), ErrorCode::Unauthorized);
+
+        emergency_alert.custodians.retain(|c| *c != caller);
+        if !emergency_alert.custodians.contains(&new_custodian) {
+            emergency_alert.custodians.push(new_custodian);
+        }
+        if emergency_alert.authority == caller {
+            emergency_alert.authority = new_custodian;
+        }
+
+        emit!(CustodianTransferred {
+            previous: caller,
+            new_custodian,
+        });
+        Ok(())
+    }
+
+    /// Raise an emergency alert.
+    ///
+    /// In plaintext mode (`encrypted == false`) the `location`/`description`
+    /// strings are stored as before. In encrypted mode the tourist supplies an
+    /// opaque blob produced off-chain: X25519 ECDH between an ephemeral key and
+    /// the emergency contact's x25519-converted key yields a shared secret that
+    /// encrypts `location||description` with XChaCha20-Poly1305. The program
+    /// only stores and emits the opaque `ephemeral_pubkey`, `nonce` and
+    /// `ciphertext`; only the contact's secret key can decrypt them off-chain.
     pub fn trigger_alert(
         ctx: Context<TriggerAlert>,
         alert_type: u8,
+        zone_id: String,
+        encrypted: bool,
         location: String,
         description: String,
+        ephemeral_pubkey: [u8; 32],
+        nonce: [u8; 24],
+        ciphertext: Vec<u8>,
     ) -> Result<()> {
         let emergency_alert = &mut ctx.accounts.emergency_alert;
         let alert = &mut ctx.accounts.alert;
@@ -30,12 +109,27 @@ pub mod emergency_alert {
         // Validate alert type
         require!(alert_type <= 2, ErrorCode::InvalidAlertType);
 
+        if encrypted {
+            // The encrypted payload must actually carry ciphertext; the
+            // plaintext fields are left empty so no position leaks on-chain.
+            require!(!ciphertext.is_empty(), ErrorCode::MissingCiphertext);
+            require!(
+                ciphertext.len() <= MAX_CIPHERTEXT_LEN,
+                ErrorCode::CiphertextTooLarge
+            );
+        }
+
         // Create alert
         alert.alert_id = emergency_alert.alert_counter;
         alert.tourist = tourist.key();
         alert.alert_type = alert_type;
-        alert.location = location.clone();
-        alert.description = description.clone();
+        alert.zone_id = zone_id.clone();
+        alert.encrypted = encrypted;
+        alert.location = if encrypted { String::new() } else { location.clone() };
+        alert.description = if encrypted { String::new() } else { description.clone() };
+        alert.ephemeral_pubkey = ephemeral_pubkey;
+        alert.nonce = nonce;
+        alert.ciphertext = ciphertext.clone();
         alert.timestamp = Clock::get()?.unix_timestamp;
         alert.is_active = true;
         alert.bump = ctx.bumps.alert;
@@ -43,13 +137,19 @@ pub mod emergency_alert {
         // Increment counter
         emergency_alert.alert_counter += 1;
 
-        // Emit event
+        // Emit event. Responders decrypt the encrypted variant straight from
+        // the event stream using the ephemeral pubkey and nonce.
         emit!(AlertTriggered {
             alert_id: alert.alert_id,
             tourist: tourist.key(),
             alert_type,
-            location,
-            description,
+            zone_id,
+            encrypted,
+            location: alert.location.clone(),
+            description: alert.description.clone(),
+            ephemeral_pubkey,
+            nonce,
+            ciphertext,
             timestamp: alert.timestamp,
         });
 
@@ -62,9 +162,16 @@ pub mod emergency_alert {
         let tourist = &ctx.accounts.tourist;
 
         require!(alert.is_active, ErrorCode::AlertAlreadyResolved);
+        // The owning tourist can always resolve their own alert; otherwise the
+        // caller must be a custodian or a delegated zone operator.
+        let caller = tourist.key();
         require!(
-            alert.tourist == tourist.key() || 
-            ctx.accounts.emergency_alert.authority == tourist.key(),
+            alert.tourist == caller
+                || ctx.accounts.emergency_alert.is_custodian(&caller)
+                || ctx
+                    .accounts
+                    .emergency_alert
+                    .is_operator_for_zone(&caller, &alert.zone_id),
             ErrorCode::Unauthorized
         );
 
@@ -162,6 +269,18 @@ pub struct ResolveAlert<'info> {
     pub tourist: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct ManageOperator<'info> {
+    #[account(
+        mut,
+        seeds = [b"emergency_alert"],
+        bump = emergency_alert.bump
+    )]
+    pub emergency_alert: Account<'info, EmergencyAlert>,
+
+    pub authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 #[instruction(contact_type: String)]
 pub struct AddEmergencyContact<'info> {
@@ -196,17 +315,59 @@ pub struct AddEmergencyContact<'info> {
 pub struct EmergencyAlert {
     pub authority: Pubkey,
     pub alert_counter: u64,
+    #[max_len(MAX_CUSTODIANS)]
+    pub custodians: Vec<Pubkey>,  // Custodian tier: config/operators/resolution
+    #[max_len(MAX_OPERATORS)]
+    pub operators: Vec<Operator>, // Operator tier: zone-scoped resolution
     pub bump: u8,
 }
 
+impl EmergencyAlert {
+    /// Custodians sit at the top of the hierarchy and may do anything.
+    pub fn is_custodian(&self, key: &Pubkey) -> bool {
+        self.custodians.contains(key)
+    }
+
+    /// Whether the key is an operator delegated for the given zone.
+    pub fn is_operator_for_zone(&self, key: &Pubkey, zone_id: &str) -> bool {
+        self.operators
+            .iter()
+            .any(|o| o.pubkey == *key && o.zone_id == zone_id)
+    }
+}
+
+/// Upper bounds on the role sets so the fixed-size config account has enough
+/// reserved space for the custodian/operator vectors.
+pub const MAX_CUSTODIANS: usize = 8;
+pub const MAX_OPERATORS: usize = 32;
+
+/// A zone-scoped operator delegated by a custodian.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Operator {
+    pub pubkey: Pubkey,
+    #[max_len(32)]
+    pub zone_id: String,
+}
+
+/// Maximum size of an encrypted alert payload (XChaCha20-Poly1305 ciphertext
+/// plus its 16-byte auth tag), keeping the account rent bounded.
+pub const MAX_CIPHERTEXT_LEN: usize = 256;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Alert {
     pub alert_id: u64,
     pub tourist: Pubkey,
-    pub alert_type: u8, // 0: PANIC, 1: GEOFENCE, 2: ANOMALY
-    pub location: String,
-    pub description: String,
+    pub alert_type: u8,          // 0: PANIC, 1: GEOFENCE, 2: ANOMALY
+    #[max_len(32)]
+    pub zone_id: String,         // Zone the alert was raised in (scopes operators)
+    pub encrypted: bool,         // Whether the payload is an encrypted blob
+    pub location: String,        // Plaintext location (empty when encrypted)
+    pub description: String,     // Plaintext description (empty when encrypted)
+    pub ephemeral_pubkey: [u8; 32], // X25519 ephemeral public key
+    pub nonce: [u8; 24],         // XChaCha20-Poly1305 nonce
+    #[max_len(MAX_CIPHERTEXT_LEN)]
+    pub ciphertext: Vec<u8>,     // Encrypted location||description
     pub timestamp: i64,
     pub is_active: bool,
     pub bump: u8,
@@ -226,8 +387,13 @@ pub struct AlertTriggered {
     pub alert_id: u64,
     pub tourist: Pubkey,
     pub alert_type: u8,
+    pub zone_id: String,
+    pub encrypted: bool,
     pub location: String,
     pub description: String,
+    pub ephemeral_pubkey: [u8; 32],
+    pub nonce: [u8; 24],
+    pub ciphertext: Vec<u8>,
     pub timestamp: i64,
 }
 
@@ -244,6 +410,23 @@ pub struct EmergencyContactAdded {
     pub contact_address: Pubkey,
 }
 
+#[event]
+pub struct OperatorAdded {
+    pub operator: Pubkey,
+    pub zone_id: String,
+}
+
+#[event]
+pub struct OperatorRemoved {
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct CustodianTransferred {
+    pub previous: Pubkey,
+    pub new_custodian: Pubkey,
+}
+
 #[error_code]
 pub enum ErrorCode {
     #[msg("Invalid alert type")]
@@ -252,4 +435,8 @@ pub enum ErrorCode {
     AlertAlreadyResolved,
     #[msg("Unauthorized")]
     Unauthorized,
+    #[msg("Encrypted alert is missing its ciphertext")]
+    MissingCiphertext,
+    #[msg("Ciphertext exceeds the maximum allowed size")]
+    CiphertextTooLarge,
 }
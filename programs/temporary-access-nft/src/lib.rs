@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, FreezeAccount, Mint, Token, TokenAccount};
 use mpl_token_metadata::{
     instructions::{CreateV1, CreateV1InstructionArgs, MintV1, MintV1InstructionArgs},
-    types::{DataV2, PrintSupply},
+    types::TokenStandard,
 };
 
 declare_id!("TempAccessNFT1111111111111111111111111111111");
@@ -17,12 +19,81 @@ pub mod temporary_access_nft {
         let program_config = &mut ctx.accounts.program_config;
         program_config.authority = ctx.accounts.authority.key();
         program_config.nft_counter = 0;
+        // The deploying authority is the first custodian (root of the
+        // Custodian/Operator/Owner hierarchy).
+        program_config.custodians = vec![ctx.accounts.authority.key()];
+        program_config.operators = vec![];
+        program_config.is_paused = false;
         program_config.bump = ctx.bumps.program_config;
-        
+
         msg!("Temporary Access NFT Program initialized");
         Ok(())
     }
 
+    /// Delegate day-to-day revocation for a single zone to an operator.
+    /// Only a custodian may add operators.
+    pub fn add_operator(
+        ctx: Context<ManageOperator>,
+        operator: Pubkey,
+        zone_id: String,
+    ) -> Result<()> {
+        let program_config = &mut ctx.accounts.program_config;
+        require!(
+            program_config.is_custodian(&ctx.accounts.authority.key()),
+            ErrorCode::Unauthorized
+        );
+
+        // Replace any existing grant so each operator holds a single zone.
+        program_config.operators.retain(|o| o.pubkey != operator);
+        program_config.operators.push(Operator {
+            pubkey: operator,
+            zone_id: zone_id.clone(),
+        });
+
+        emit!(OperatorAdded { operator, zone_id });
+        Ok(())
+    }
+
+    /// Remove a delegated operator. Only a custodian may remove operators.
+    pub fn remove_operator(ctx: Context<ManageOperator>, operator: Pubkey) -> Result<()> {
+        let program_config = &mut ctx.accounts.program_config;
+        require!(
+            program_config.is_custodian(&ctx.accounts.authority.key()),
+            ErrorCode::Unauthorized
+        );
+
+        program_config.operators.retain(|o| o.pubkey != operator);
+
+        emit!(OperatorRemoved { operator });
+        Ok(())
+    }
+
+    /// Hand the custodian role to a new principal. The caller must already be
+    /// a custodian; it is swapped out for the new one.
+    pub fn transfer_custodian(
+        ctx: Context<ManageOperator>,
+        new_custodian: Pubkey,
+    ) -> Result<()> {
+        let program_config = &mut ctx.accounts.program_config;
+        let caller = ctx.accounts.authority.key();
+        require!(program_config.is_custodian(&caller), ErrorCode::Unauthorized);
+
+        program_config.custodians.retain(|c| *c != caller);
+        if !program_config.custodians.contains(&new_custodian) {
+            program_config.custodians.push(new_custodian);
+        }
+        // Keep `authority` pointing at a valid custodian for compatibility.
+        if program_config.authority == caller {
+            program_config.authority = new_custodian;
+        }
+
+        emit!(CustodianTransferred {
+            previous: caller,
+            new_custodian,
+        });
+        Ok(())
+    }
+
     /// Mint a temporary access NFT for a tourist
     /// This creates a soulbound NFT that cannot be transferred
     pub fn mint_access_nft(
@@ -30,29 +101,146 @@ pub mod temporary_access_nft {
         tourist_id_hash: [u8; 32],
         zone_id: String,
         expiry_timestamp: i64,
+        activation_timestamp: i64,
         metadata_uri: String,
     ) -> Result<()> {
-        let program_config = &mut ctx.accounts.program_config;
         let access_nft = &mut ctx.accounts.access_nft;
         let tourist = &ctx.accounts.tourist;
 
+        // No new passes may be minted while the program is paused.
+        require!(!ctx.accounts.program_config.is_paused, ErrorCode::ProgramPaused);
+
         // Validate expiry timestamp is in the future
         let current_time = Clock::get()?.unix_timestamp;
         require!(expiry_timestamp > current_time, ErrorCode::InvalidExpiryTime);
+        // A graduated (pre-booked) pass may activate in the future, but must
+        // still unlock before it expires. `0` means active immediately.
+        require!(
+            activation_timestamp < expiry_timestamp,
+            ErrorCode::InvalidActivationTime
+        );
+
+        let nft_id = ctx.accounts.program_config.nft_counter;
+        let config_bump = ctx.accounts.program_config.bump;
+        let config_seeds: &[&[u8]] = &[b"program_config", &[config_bump]];
+        let signer_seeds = &[config_seeds];
 
-        // Create the access NFT account
-        access_nft.nft_id = program_config.nft_counter;
+        // 1. Create the metadata for the already-initialized mint as a
+        //    FungibleAsset with zero decimals. This standard needs no master edition, so the mint's
+        //    freeze authority stays with `program_config` and we can soulbind
+        //    the single-supply pass by freezing it in step 3. (`print_supply`
+        //    only applies to the NonFungible standard, hence `None` here.)
+        let create_ix = CreateV1 {
+            metadata: ctx.accounts.metadata.key(),
+            master_edition: None,
+            mint: (ctx.accounts.mint.key(), false),
+            authority: ctx.accounts.program_config.key(),
+            payer: tourist.key(),
+            update_authority: (ctx.accounts.program_config.key(), true),
+            system_program: ctx.accounts.system_program.key(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.key(),
+            spl_token_program: Some(ctx.accounts.token_program.key()),
+        }
+        .instruction(CreateV1InstructionArgs {
+            name: format!("Tourist Access Pass #{}", nft_id),
+            symbol: "TAP".to_string(),
+            uri: metadata_uri.clone(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            primary_sale_happened: false,
+            is_mutable: true,
+            token_standard: TokenStandard::FungibleAsset,
+            collection: None,
+            uses: None,
+            collection_details: None,
+            rule_set: None,
+            decimals: Some(0),
+            print_supply: None,
+        });
+        invoke_signed(
+            &create_ix,
+            &[
+                ctx.accounts.token_metadata_program.to_account_info(),
+                ctx.accounts.metadata.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.program_config.to_account_info(),
+                tourist.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.sysvar_instructions.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+
+        // 2. Mint exactly one token into the tourist's associated token account.
+        let mint_ix = MintV1 {
+            token: ctx.accounts.tourist_token_account.key(),
+            token_owner: Some(tourist.key()),
+            metadata: ctx.accounts.metadata.key(),
+            master_edition: None,
+            token_record: None,
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.program_config.key(),
+            delegate_record: None,
+            payer: tourist.key(),
+            system_program: ctx.accounts.system_program.key(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.key(),
+            spl_token_program: ctx.accounts.token_program.key(),
+            spl_ata_program: ctx.accounts.associated_token_program.key(),
+        }
+        .instruction(MintV1InstructionArgs {
+            amount: 1,
+            authorization_data: None,
+        });
+        invoke_signed(
+            &mint_ix,
+            &[
+                ctx.accounts.token_metadata_program.to_account_info(),
+                ctx.accounts.tourist_token_account.to_account_info(),
+                tourist.to_account_info(),
+                ctx.accounts.metadata.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.program_config.to_account_info(),
+                tourist.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.sysvar_instructions.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.associated_token_program.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+
+        // 3. Enforce soulboundness: freeze the tourist's token account so the
+        //    pass can never be transferred to another wallet. `program_config`
+        //    is still the mint's freeze authority because we skipped the
+        //    master edition above.
+        token::freeze_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                account: ctx.accounts.tourist_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.program_config.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+
+        // Record the access NFT PDA, cross-referencing the real on-chain mint.
+        access_nft.nft_id = nft_id;
         access_nft.tourist_id_hash = tourist_id_hash;
         access_nft.zone_id = zone_id.clone();
         access_nft.expiry_timestamp = expiry_timestamp;
+        access_nft.activation_timestamp = activation_timestamp;
         access_nft.tourist_wallet = tourist.key();
+        access_nft.mint = ctx.accounts.mint.key();
         access_nft.is_valid = true;
+        access_nft.is_frozen = false;
+        access_nft.is_revoked = false;
         access_nft.metadata_uri = metadata_uri.clone();
         access_nft.minted_at = current_time;
         access_nft.bump = ctx.bumps.access_nft;
 
         // Increment NFT counter
-        program_config.nft_counter += 1;
+        ctx.accounts.program_config.nft_counter += 1;
 
         // Emit event for tracking
         emit!(AccessNftMinted {
@@ -69,8 +257,11 @@ pub mod temporary_access_nft {
         Ok(())
     }
 
-    /// Verify if a tourist has valid access to a specific zone
-    /// Returns true if the NFT exists, is valid, and not expired
+    /// Verify if a tourist has valid access to a specific zone.
+    ///
+    /// This is a read-only, permissionless check: it does not mutate any
+    /// account and requires no signer, so anyone can query a pass for free.
+    /// To persist an attempt to the on-chain history, call `record_verification`.
     pub fn verify_access(
         ctx: Context<VerifyAccess>,
         tourist_wallet: Pubkey,
@@ -79,17 +270,19 @@ pub mod temporary_access_nft {
         let access_nft = &ctx.accounts.access_nft;
         let current_time = Clock::get()?.unix_timestamp;
 
-        // Check if NFT is valid and not expired
-        let is_valid = access_nft.is_valid 
-            && access_nft.tourist_wallet == tourist_wallet
-            && access_nft.zone_id == zone_id
-            && access_nft.expiry_timestamp > current_time;
+        let is_valid = evaluate_access(
+            access_nft,
+            ctx.accounts.program_config.is_paused,
+            &tourist_wallet,
+            &zone_id,
+            current_time,
+        );
 
         // Emit verification event
         emit!(AccessVerified {
             nft_id: access_nft.nft_id,
             tourist_wallet,
-            zone_id,
+            zone_id: zone_id.clone(),
             is_valid,
             verified_at: current_time,
         });
@@ -98,17 +291,87 @@ pub mod temporary_access_nft {
         Ok(is_valid)
     }
 
+    /// Verify access and persist the attempt to the pass's on-chain history.
+    ///
+    /// Unlike `verify_access` this mutates state: it requires a funded
+    /// `verifier` signer to pay rent for the log the first time it is written.
+    /// Returns the same verdict as `verify_access`.
+    pub fn record_verification(
+        ctx: Context<RecordVerification>,
+        tourist_wallet: Pubkey,
+        zone_id: String,
+    ) -> Result<bool> {
+        let access_nft = &ctx.accounts.access_nft;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        let is_valid = evaluate_access(
+            access_nft,
+            ctx.accounts.program_config.is_paused,
+            &tourist_wallet,
+            &zone_id,
+            current_time,
+        );
+
+        // Append this attempt to the on-chain verification history. The log is
+        // a bounded ring buffer so auditors can reconstruct scans even after
+        // emitted events are pruned, while keeping rent constant.
+        let nft_id = access_nft.nft_id;
+        let log = &mut ctx.accounts.verification_log;
+        if log.nft_id == 0 && log.total_checks == 0 {
+            log.nft_id = nft_id;
+            log.bump = ctx.bumps.verification_log;
+        }
+        log.total_checks = log.total_checks.saturating_add(1);
+        if !is_valid {
+            log.total_denials = log.total_denials.saturating_add(1);
+        }
+        let entry = VerificationEntry {
+            timestamp: current_time,
+            verifier: ctx.accounts.verifier.key(),
+            zone_id: zone_id.clone(),
+            passed: is_valid,
+        };
+        if log.entries.len() < MAX_LOG_ENTRIES {
+            log.entries.push(entry);
+        } else {
+            let head = log.head as usize;
+            log.entries[head] = entry;
+            log.head = ((head + 1) % MAX_LOG_ENTRIES) as u16;
+        }
+
+        emit!(AccessVerified {
+            nft_id,
+            tourist_wallet,
+            zone_id: zone_id.clone(),
+            is_valid,
+            verified_at: current_time,
+        });
+
+        msg!("Access verification recorded for zone {}: {}", zone_id, is_valid);
+        Ok(is_valid)
+    }
+
     /// Revoke access pass by authority (tourism department)
     /// This invalidates the NFT before its natural expiry
     pub fn revoke_pass(ctx: Context<RevokePass>) -> Result<()> {
-        let access_nft = &mut ctx.accounts.access_nft;
         let authority = &ctx.accounts.authority;
 
+        // A custodian may revoke any pass; an operator only within their zone.
+        require!(
+            ctx.accounts
+                .program_config
+                .can_revoke(&authority.key(), &ctx.accounts.access_nft.zone_id),
+            ErrorCode::Unauthorized
+        );
+
+        let access_nft = &mut ctx.accounts.access_nft;
+
         // Check if NFT is currently valid
         require!(access_nft.is_valid, ErrorCode::PassAlreadyRevoked);
 
-        // Mark as invalid
+        // Mark as invalid and permanently revoked so it cannot be renewed.
         access_nft.is_valid = false;
+        access_nft.is_revoked = true;
 
         // Emit revocation event
         emit!(PassRevoked {
@@ -128,8 +391,15 @@ pub mod temporary_access_nft {
         ctx: Context<UpdateMetadata>,
         new_metadata_uri: String,
     ) -> Result<()> {
+        require!(
+            ctx.accounts
+                .program_config
+                .is_custodian(&ctx.accounts.authority.key()),
+            ErrorCode::Unauthorized
+        );
+
         let access_nft = &mut ctx.accounts.access_nft;
-        
+
         access_nft.metadata_uri = new_metadata_uri.clone();
 
         emit!(MetadataUpdated {
@@ -141,6 +411,222 @@ pub mod temporary_access_nft {
         msg!("Metadata updated for NFT ID {}", access_nft.nft_id);
         Ok(())
     }
+
+    /// Temporarily suspend a pass (e.g. during an investigation) without the
+    /// permanence of `revoke_pass`. A custodian or the zone operator may act.
+    pub fn freeze_pass(ctx: Context<FreezePass>) -> Result<()> {
+        require!(
+            ctx.accounts
+                .program_config
+                .can_revoke(&ctx.accounts.authority.key(), &ctx.accounts.access_nft.zone_id),
+            ErrorCode::Unauthorized
+        );
+
+        let access_nft = &mut ctx.accounts.access_nft;
+        require!(!access_nft.is_frozen, ErrorCode::PassAlreadyFrozen);
+        access_nft.is_frozen = true;
+
+        emit!(PassFrozen {
+            nft_id: access_nft.nft_id,
+            tourist_wallet: access_nft.tourist_wallet,
+            zone_id: access_nft.zone_id.clone(),
+            frozen_by: ctx.accounts.authority.key(),
+            frozen_at: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Access pass frozen: NFT ID {}", access_nft.nft_id);
+        Ok(())
+    }
+
+    /// Lift a suspension applied by `freeze_pass`, restoring the pass.
+    pub fn unfreeze_pass(ctx: Context<FreezePass>) -> Result<()> {
+        require!(
+            ctx.accounts
+                .program_config
+                .can_revoke(&ctx.accounts.authority.key(), &ctx.accounts.access_nft.zone_id),
+            ErrorCode::Unauthorized
+        );
+
+        let access_nft = &mut ctx.accounts.access_nft;
+        require!(access_nft.is_frozen, ErrorCode::PassNotFrozen);
+        access_nft.is_frozen = false;
+
+        emit!(PassUnfrozen {
+            nft_id: access_nft.nft_id,
+            tourist_wallet: access_nft.tourist_wallet,
+            zone_id: access_nft.zone_id.clone(),
+            unfrozen_by: ctx.accounts.authority.key(),
+            unfrozen_at: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Access pass unfrozen: NFT ID {}", access_nft.nft_id);
+        Ok(())
+    }
+
+    /// Pause the whole program during an emergency lockdown: new mints are
+    /// blocked and every verification fails fast. Custodian-only.
+    pub fn pause_program(ctx: Context<PauseProgram>) -> Result<()> {
+        let program_config = &mut ctx.accounts.program_config;
+        require!(
+            program_config.is_custodian(&ctx.accounts.authority.key()),
+            ErrorCode::Unauthorized
+        );
+        program_config.is_paused = true;
+
+        emit!(ProgramPaused {
+            paused: true,
+            changed_by: ctx.accounts.authority.key(),
+            changed_at: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Program paused");
+        Ok(())
+    }
+
+    /// Resume normal operation after a `pause_program`. Custodian-only.
+    pub fn unpause_program(ctx: Context<PauseProgram>) -> Result<()> {
+        let program_config = &mut ctx.accounts.program_config;
+        require!(
+            program_config.is_custodian(&ctx.accounts.authority.key()),
+            ErrorCode::Unauthorized
+        );
+        program_config.is_paused = false;
+
+        emit!(ProgramPaused {
+            paused: false,
+            changed_by: ctx.accounts.authority.key(),
+            changed_at: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Program unpaused");
+        Ok(())
+    }
+
+    /// Clear a verification log's ring buffer and counters without closing the
+    /// account, so it can keep accumulating from a clean slate. Custodian-only.
+    pub fn reset_verification_log(ctx: Context<ResetVerificationLog>) -> Result<()> {
+        require!(
+            ctx.accounts
+                .program_config
+                .is_custodian(&ctx.accounts.authority.key()),
+            ErrorCode::Unauthorized
+        );
+
+        let log = &mut ctx.accounts.verification_log;
+        log.entries.clear();
+        log.head = 0;
+        log.total_checks = 0;
+        log.total_denials = 0;
+
+        msg!("Verification log reset for NFT ID {}", log.nft_id);
+        Ok(())
+    }
+
+    /// Close a verification log and refund its rent to the authority.
+    /// Custodian-only.
+    pub fn close_verification_log(ctx: Context<CloseVerificationLog>) -> Result<()> {
+        require!(
+            ctx.accounts
+                .program_config
+                .is_custodian(&ctx.accounts.authority.key()),
+            ErrorCode::Unauthorized
+        );
+
+        msg!(
+            "Verification log closed for NFT ID {}",
+            ctx.accounts.verification_log.nft_id
+        );
+        Ok(())
+    }
+
+    /// Extend a pass instead of re-minting it: push `expiry_timestamp` forward
+    /// by `duration` seconds, revive it if it had naturally expired, and keep
+    /// it valid. A custodian (or the configured authority) may renew.
+    pub fn renew_pass(ctx: Context<RenewPass>, duration: i64) -> Result<()> {
+        require!(
+            ctx.accounts
+                .program_config
+                .is_custodian(&ctx.accounts.authority.key()),
+            ErrorCode::Unauthorized
+        );
+        require!(duration > 0, ErrorCode::InvalidRenewalDuration);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let access_nft = &mut ctx.accounts.access_nft;
+
+        // A permanently revoked pass can never be renewed back to life; only a
+        // natural lapse (expiry in the past) may be revived.
+        require!(!access_nft.is_revoked, ErrorCode::PassAlreadyRevoked);
+
+        let new_expiry = access_nft
+            .expiry_timestamp
+            .checked_add(duration)
+            .ok_or(ErrorCode::InvalidRenewalDuration)?;
+        require!(new_expiry > current_time, ErrorCode::InvalidExpiryTime);
+
+        access_nft.expiry_timestamp = new_expiry;
+        // Natural expiry never clears `is_valid` (only revocation and
+        // surrender do, and both were rejected above), so the pass stays valid.
+        access_nft.is_valid = true;
+
+        emit!(PassRenewed {
+            nft_id: access_nft.nft_id,
+            tourist_wallet: access_nft.tourist_wallet,
+            new_expiry,
+            renewed_by: ctx.accounts.authority.key(),
+            renewed_at: current_time,
+        });
+
+        msg!("Access pass renewed: NFT ID {}", access_nft.nft_id);
+        Ok(())
+    }
+
+    /// Owner-tier action: the tourist voluntarily surrenders their own pass
+    /// (e.g. leaving the zone early). Only the pass owner may call this, and
+    /// the surrender is permanent so it cannot be renewed afterwards.
+    pub fn surrender_pass(ctx: Context<SurrenderPass>) -> Result<()> {
+        let access_nft = &mut ctx.accounts.access_nft;
+
+        // The signer must be the owner recorded on the pass.
+        require!(
+            access_nft.tourist_wallet == ctx.accounts.owner.key(),
+            ErrorCode::Unauthorized
+        );
+        require!(access_nft.is_valid, ErrorCode::PassAlreadyRevoked);
+
+        access_nft.is_valid = false;
+        access_nft.is_revoked = true;
+
+        emit!(PassSurrendered {
+            nft_id: access_nft.nft_id,
+            tourist_wallet: access_nft.tourist_wallet,
+            zone_id: access_nft.zone_id.clone(),
+            surrendered_at: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Access pass surrendered by owner: NFT ID {}", access_nft.nft_id);
+        Ok(())
+    }
+}
+
+// Shared helpers
+
+/// Evaluate whether a pass currently grants access, shared by the read-only
+/// `verify_access` and the state-writing `record_verification`.
+fn evaluate_access(
+    access_nft: &AccessNft,
+    is_paused: bool,
+    tourist_wallet: &Pubkey,
+    zone_id: &str,
+    current_time: i64,
+) -> bool {
+    access_nft.is_valid
+        && !access_nft.is_frozen
+        && !is_paused
+        && access_nft.tourist_wallet == *tourist_wallet
+        && access_nft.zone_id == zone_id
+        && current_time >= access_nft.activation_timestamp
+        && access_nft.expiry_timestamp > current_time
 }
 
 // Account validation structs
@@ -180,10 +666,45 @@ pub struct MintAccessNft<'info> {
         bump
     )]
     pub access_nft: Account<'info, AccessNft>,
-    
+
+    /// Mint of the soulbound pass. Initialized here by Anchor with
+    /// `program_config` as both mint and freeze authority; the token-metadata
+    /// CPI then attaches metadata to it.
+    #[account(
+        init,
+        payer = tourist,
+        mint::decimals = 0,
+        mint::authority = program_config,
+        mint::freeze_authority = program_config,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: metadata PDA, validated and written by the token-metadata program.
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = tourist,
+        associated_token::mint = mint,
+        associated_token::authority = tourist,
+    )]
+    pub tourist_token_account: Account<'info, TokenAccount>,
+
     #[account(mut)]
     pub tourist: Signer<'info>,
-    
+
+    /// CHECK: address checked against the Metaplex token-metadata program id.
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    /// CHECK: instructions sysvar required by the token-metadata CPIs.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
     pub system_program: Program<'info, System>,
 }
 
@@ -197,6 +718,160 @@ pub struct VerifyAccess<'info> {
         constraint = access_nft.zone_id == zone_id
     )]
     pub access_nft: Account<'info, AccessNft>,
+
+    /// The tourist's token account, reconciled against the real on-chain NFT
+    /// recorded on the PDA so the two can never silently diverge.
+    #[account(
+        constraint = tourist_token_account.mint == access_nft.mint @ ErrorCode::TokenMintMismatch,
+        constraint = tourist_token_account.owner == tourist_wallet @ ErrorCode::TokenMintMismatch,
+        constraint = tourist_token_account.amount == 1 @ ErrorCode::TokenMintMismatch,
+    )]
+    pub tourist_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(tourist_wallet: Pubkey, zone_id: String)]
+pub struct RecordVerification<'info> {
+    #[account(
+        seeds = [b"access_nft", access_nft.nft_id.to_le_bytes().as_ref()],
+        bump = access_nft.bump,
+        constraint = access_nft.tourist_wallet == tourist_wallet,
+        constraint = access_nft.zone_id == zone_id
+    )]
+    pub access_nft: Account<'info, AccessNft>,
+
+    /// The tourist's token account, reconciled against the real on-chain NFT
+    /// recorded on the PDA so the two can never silently diverge.
+    #[account(
+        constraint = tourist_token_account.mint == access_nft.mint @ ErrorCode::TokenMintMismatch,
+        constraint = tourist_token_account.owner == tourist_wallet @ ErrorCode::TokenMintMismatch,
+        constraint = tourist_token_account.amount == 1 @ ErrorCode::TokenMintMismatch,
+    )]
+    pub tourist_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = 8 + VerificationLog::INIT_SPACE,
+        seeds = [b"verify_log", access_nft.nft_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub verification_log: Account<'info, VerificationLog>,
+
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResetVerificationLog<'info> {
+    #[account(
+        mut,
+        seeds = [b"verify_log", verification_log.nft_id.to_le_bytes().as_ref()],
+        bump = verification_log.bump
+    )]
+    pub verification_log: Account<'info, VerificationLog>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseVerificationLog<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"verify_log", verification_log.nft_id.to_le_bytes().as_ref()],
+        bump = verification_log.bump
+    )]
+    pub verification_log: Account<'info, VerificationLog>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FreezePass<'info> {
+    #[account(
+        mut,
+        seeds = [b"access_nft", access_nft.nft_id.to_le_bytes().as_ref()],
+        bump = access_nft.bump
+    )]
+    pub access_nft: Account<'info, AccessNft>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RenewPass<'info> {
+    #[account(
+        mut,
+        seeds = [b"access_nft", access_nft.nft_id.to_le_bytes().as_ref()],
+        bump = access_nft.bump
+    )]
+    pub access_nft: Account<'info, AccessNft>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SurrenderPass<'info> {
+    #[account(
+        mut,
+        seeds = [b"access_nft", access_nft.nft_id.to_le_bytes().as_ref()],
+        bump = access_nft.bump
+    )]
+    pub access_nft: Account<'info, AccessNft>,
+
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PauseProgram<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
@@ -207,15 +882,22 @@ pub struct RevokePass<'info> {
         bump = access_nft.bump
     )]
     pub access_nft: Account<'info, AccessNft>,
-    
+
+    /// The tourist's token account, cross-checked against the mint recorded on
+    /// the PDA so a revocation always targets the real on-chain NFT.
+    #[account(
+        constraint = tourist_token_account.mint == access_nft.mint @ ErrorCode::TokenMintMismatch,
+        constraint = tourist_token_account.owner == access_nft.tourist_wallet @ ErrorCode::TokenMintMismatch,
+    )]
+    pub tourist_token_account: Account<'info, TokenAccount>,
+
     #[account(
         mut,
         seeds = [b"program_config"],
-        bump = program_config.bump,
-        constraint = program_config.authority == authority.key()
+        bump = program_config.bump
     )]
     pub program_config: Account<'info, ProgramConfig>,
-    
+
     pub authority: Signer<'info>,
 }
 
@@ -227,15 +909,26 @@ pub struct UpdateMetadata<'info> {
         bump = access_nft.bump
     )]
     pub access_nft: Account<'info, AccessNft>,
-    
+
     #[account(
         mut,
         seeds = [b"program_config"],
-        bump = program_config.bump,
-        constraint = program_config.authority == authority.key()
+        bump = program_config.bump
     )]
     pub program_config: Account<'info, ProgramConfig>,
-    
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ManageOperator<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
     pub authority: Signer<'info>,
 }
 
@@ -246,9 +939,73 @@ pub struct UpdateMetadata<'info> {
 pub struct ProgramConfig {
     pub authority: Pubkey,
     pub nft_counter: u64,
+    #[max_len(MAX_CUSTODIANS)]
+    pub custodians: Vec<Pubkey>,  // Custodian tier: revoke/config/operators (minting is not role-gated)
+    #[max_len(MAX_OPERATORS)]
+    pub operators: Vec<Operator>, // Operator tier: zone-scoped revocation
+    pub is_paused: bool,          // Emergency lockdown: blocks mints/verification
     pub bump: u8,
 }
 
+impl ProgramConfig {
+    /// Custodians sit at the top of the hierarchy and may do anything.
+    pub fn is_custodian(&self, key: &Pubkey) -> bool {
+        self.custodians.contains(key)
+    }
+
+    /// An operator is authorized only for the zone they were delegated.
+    pub fn is_operator_for_zone(&self, key: &Pubkey, zone_id: &str) -> bool {
+        self.operators
+            .iter()
+            .any(|o| o.pubkey == *key && o.zone_id == zone_id)
+    }
+
+    /// Revocation is allowed for custodians (any zone) or the zone operator.
+    pub fn can_revoke(&self, key: &Pubkey, zone_id: &str) -> bool {
+        self.is_custodian(key) || self.is_operator_for_zone(key, zone_id)
+    }
+}
+
+/// Upper bounds on the role sets so the fixed-size config account has enough
+/// reserved space for the custodian/operator vectors.
+pub const MAX_CUSTODIANS: usize = 8;
+pub const MAX_OPERATORS: usize = 32;
+
+/// A zone-scoped operator delegated by a custodian.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Operator {
+    pub pubkey: Pubkey,
+    #[max_len(32)]
+    pub zone_id: String,
+}
+
+/// Maximum number of verification attempts retained per pass. The log is a
+/// ring buffer, so rent stays bounded regardless of how often a pass is scanned.
+pub const MAX_LOG_ENTRIES: usize = 16;
+
+/// Append-only (ring) history of verification attempts for a single pass.
+#[account]
+#[derive(InitSpace)]
+pub struct VerificationLog {
+    pub nft_id: u64,        // Pass this log belongs to
+    pub total_checks: u64,  // Running count of all verification attempts
+    pub total_denials: u64, // Running count of attempts that failed
+    pub head: u16,          // Ring-buffer write cursor once at capacity
+    #[max_len(MAX_LOG_ENTRIES)]
+    pub entries: Vec<VerificationEntry>,
+    pub bump: u8,
+}
+
+/// A single recorded verification attempt.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct VerificationEntry {
+    pub timestamp: i64,
+    pub verifier: Pubkey,
+    #[max_len(32)]
+    pub zone_id: String,
+    pub passed: bool,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct AccessNft {
@@ -256,8 +1013,12 @@ pub struct AccessNft {
     pub tourist_id_hash: [u8; 32], // Soulbound binding to tourist digital ID
     pub zone_id: String,           // Restricted area/park/site identifier
     pub expiry_timestamp: i64,     // Unix time after which access is revoked
+    pub activation_timestamp: i64, // Unix time before which access is not yet valid (0 = immediate)
     pub tourist_wallet: Pubkey,    // Tourist's wallet address
+    pub mint: Pubkey,              // On-chain mint of the soulbound pass
     pub is_valid: bool,            // Whether the pass is currently valid
+    pub is_frozen: bool,           // Reversible suspension, distinct from revoke
+    pub is_revoked: bool,          // Permanent revocation; renewal cannot undo it
     pub metadata_uri: String,      // IPFS/Arweave metadata URI
     pub minted_at: i64,            // When the NFT was minted
     pub bump: u8,
@@ -301,6 +1062,65 @@ pub struct MetadataUpdated {
     pub updated_at: i64,
 }
 
+#[event]
+pub struct OperatorAdded {
+    pub operator: Pubkey,
+    pub zone_id: String,
+}
+
+#[event]
+pub struct OperatorRemoved {
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct CustodianTransferred {
+    pub previous: Pubkey,
+    pub new_custodian: Pubkey,
+}
+
+#[event]
+pub struct PassFrozen {
+    pub nft_id: u64,
+    pub tourist_wallet: Pubkey,
+    pub zone_id: String,
+    pub frozen_by: Pubkey,
+    pub frozen_at: i64,
+}
+
+#[event]
+pub struct PassUnfrozen {
+    pub nft_id: u64,
+    pub tourist_wallet: Pubkey,
+    pub zone_id: String,
+    pub unfrozen_by: Pubkey,
+    pub unfrozen_at: i64,
+}
+
+#[event]
+pub struct ProgramPaused {
+    pub paused: bool,
+    pub changed_by: Pubkey,
+    pub changed_at: i64,
+}
+
+#[event]
+pub struct PassRenewed {
+    pub nft_id: u64,
+    pub tourist_wallet: Pubkey,
+    pub new_expiry: i64,
+    pub renewed_by: Pubkey,
+    pub renewed_at: i64,
+}
+
+#[event]
+pub struct PassSurrendered {
+    pub nft_id: u64,
+    pub tourist_wallet: Pubkey,
+    pub zone_id: String,
+    pub surrendered_at: i64,
+}
+
 // Error codes
 
 #[error_code]
@@ -315,4 +1135,16 @@ pub enum ErrorCode {
     NftNotFound,
     #[msg("Access expired")]
     AccessExpired,
+    #[msg("Pass is already frozen")]
+    PassAlreadyFrozen,
+    #[msg("Pass is not frozen")]
+    PassNotFrozen,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Token account does not match the mint recorded on the pass")]
+    TokenMintMismatch,
+    #[msg("Invalid activation time - must be before expiry")]
+    InvalidActivationTime,
+    #[msg("Invalid renewal duration")]
+    InvalidRenewalDuration,
 }